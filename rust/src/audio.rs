@@ -1,4 +1,5 @@
 use crate::config::{AudioConfig, MusicConfig};
+use crate::tuning::Tuning;
 use anyhow::{anyhow, Result};
 use rodio::{source::Source, OutputStream, OutputStreamHandle, Sink};
 use std::collections::HashMap;
@@ -10,18 +11,59 @@ pub struct PianoSound {
     music_config: MusicConfig,
     instrument: String,
     basetone: String,
+    tuning: Tuning,
     volume: f32,
     duration: f32,
     sample_rate: u32,
     _stream: OutputStream,
     #[allow(dead_code)]
     stream_handle: OutputStreamHandle,
-    sink: Arc<Mutex<Sink>>,
-    note_to_semitones: HashMap<String, i32>,
+    /// One `Sink` per currently-sounding note, keyed by note name. All voices
+    /// share the same output stream, so they mix together automatically —
+    /// unlike a single shared sink, starting one note never stops another.
+    voices: Arc<Mutex<HashMap<String, Sink>>>,
+    note_to_steps: HashMap<String, i32>,
     instrument_cache: HashMap<String, Vec<f32>>,
     instruments: Vec<String>,
 }
 
+/// The base-octave scale-degree names and the 12-TET semitone each has
+/// always stood for: a diatonic major scale ("1".."7") with the five
+/// chromatic in-between notes ("#1", "#2", "#4", "#5", "#6").
+const BASE_DEGREES: [(&str, i32); 12] = [
+    ("1", 0), ("#1", 1), ("2", 2), ("#2", 3), ("3", 4), ("4", 5),
+    ("#4", 6), ("5", 7), ("#5", 8), ("6", 9), ("#6", 10), ("7", 11),
+];
+
+/// Build the key->step table for a tuning with `steps_per_period` scale
+/// steps. Each degree's 12-TET semitone is rescaled proportionally onto the
+/// new step count (e.g. the fifth, 7 of 12 semitones, becomes 11 of 19 steps
+/// in 19-EDO) so the keyboard lands on that tuning's own scale steps rather
+/// than reusing fixed 12-TET semitone numbers. "." and "^" shift by one full
+/// period, whatever that tuning's period spans.
+fn build_note_to_steps(steps_per_period: u32) -> HashMap<String, i32> {
+    let mut note_to_steps = HashMap::new();
+    let steps_per_period = steps_per_period as i32;
+
+    for (name, semitone) in BASE_DEGREES {
+        let step = ((semitone * steps_per_period) as f32 / 12.0).round() as i32;
+        note_to_steps.insert(name.to_string(), step);
+        note_to_steps.insert(format!(".{}", name), step - steps_per_period);
+        note_to_steps.insert(format!("^{}", name), step + steps_per_period);
+    }
+
+    note_to_steps
+}
+
+/// Parse a raw tuning-step note name like `"@7"` or `"@-12"`. Isomorphic
+/// keyboard layouts (see `KeyboardLayout::from_isomorphic`) name their keys
+/// this way instead of via the scale-degree vocabulary, since their grids
+/// routinely span more steps than that vocabulary's three registers can
+/// hold without collisions, and a raw step is valid under any tuning.
+fn parse_raw_step(note: &str) -> Option<i32> {
+    note.strip_prefix('@')?.parse().ok()
+}
+
 impl PianoSound {
     pub fn new(
         sample_rate: Option<u32>,
@@ -29,9 +71,11 @@ impl PianoSound {
         instrument: Option<String>,
         basetone: Option<String>,
         volume: Option<f32>,
+        tuning: Option<Tuning>,
     ) -> Result<Self> {
         let config = AudioConfig::default();
         let music_config = MusicConfig::default();
+        let tuning = tuning.unwrap_or_default();
 
         let sample_rate = sample_rate.unwrap_or(config.default_sample_rate);
         let duration = duration.unwrap_or(config.gui_duration);
@@ -58,67 +102,23 @@ impl PianoSound {
         let (_stream, stream_handle) = OutputStream::try_default()
             .map_err(|e| anyhow!("Failed to create audio stream: {}", e))?;
 
-        let sink = Arc::new(Mutex::new(
-            Sink::try_new(&stream_handle)
-                .map_err(|e| anyhow!("Failed to create audio sink: {}", e))?,
-        ));
+        let voices = Arc::new(Mutex::new(HashMap::new()));
 
-        let mut note_to_semitones = HashMap::new();
-        
-        // Low octave (one octave below base)
-        note_to_semitones.insert(".1".to_string(), -12);
-        note_to_semitones.insert(".#1".to_string(), -11);
-        note_to_semitones.insert(".2".to_string(), -10);
-        note_to_semitones.insert(".#2".to_string(), -9);
-        note_to_semitones.insert(".3".to_string(), -8);
-        note_to_semitones.insert(".4".to_string(), -7);
-        note_to_semitones.insert(".#4".to_string(), -6);
-        note_to_semitones.insert(".5".to_string(), -5);
-        note_to_semitones.insert(".#5".to_string(), -4);
-        note_to_semitones.insert(".6".to_string(), -3);
-        note_to_semitones.insert(".#6".to_string(), -2);
-        note_to_semitones.insert(".7".to_string(), -1);
-
-        // Base octave
-        note_to_semitones.insert("1".to_string(), 0);
-        note_to_semitones.insert("#1".to_string(), 1);
-        note_to_semitones.insert("2".to_string(), 2);
-        note_to_semitones.insert("#2".to_string(), 3);
-        note_to_semitones.insert("3".to_string(), 4);
-        note_to_semitones.insert("4".to_string(), 5);
-        note_to_semitones.insert("#4".to_string(), 6);
-        note_to_semitones.insert("5".to_string(), 7);
-        note_to_semitones.insert("#5".to_string(), 8);
-        note_to_semitones.insert("6".to_string(), 9);
-        note_to_semitones.insert("#6".to_string(), 10);
-        note_to_semitones.insert("7".to_string(), 11);
-
-        // High octave (one octave above base)
-        note_to_semitones.insert("^1".to_string(), 12);
-        note_to_semitones.insert("^#1".to_string(), 13);
-        note_to_semitones.insert("^2".to_string(), 14);
-        note_to_semitones.insert("^#2".to_string(), 15);
-        note_to_semitones.insert("^3".to_string(), 16);
-        note_to_semitones.insert("^4".to_string(), 17);
-        note_to_semitones.insert("^#4".to_string(), 18);
-        note_to_semitones.insert("^5".to_string(), 19);
-        note_to_semitones.insert("^#5".to_string(), 20);
-        note_to_semitones.insert("^6".to_string(), 21);
-        note_to_semitones.insert("^#6".to_string(), 22);
-        note_to_semitones.insert("^7".to_string(), 23);
+        let note_to_steps = build_note_to_steps(tuning.steps.len() as u32);
 
         let mut piano_sound = Self {
             config,
             music_config,
             instrument,
             basetone,
+            tuning,
             volume,
             duration,
             sample_rate,
             _stream,
             stream_handle,
-            sink,
-            note_to_semitones,
+            voices,
+            note_to_steps,
             instrument_cache: HashMap::new(),
             instruments,
         };
@@ -130,23 +130,29 @@ impl PianoSound {
 
     fn pregenerate_waveforms(&mut self) -> Result<()> {
         log::info!("Pregenerating waveforms for all notes...");
-        
-        for (note, semitones) in &self.note_to_semitones.clone() {
-            let waveform = self.generate_waveform(note, *semitones)?;
+
+        // Raw-step notes (isomorphic layouts) are cached lazily in
+        // `play_note` instead of here, since there's no fixed list of them;
+        // clear those out too so a basetone/instrument/tuning change doesn't
+        // leave them sounding with stale settings.
+        self.instrument_cache.clear();
+
+        for (note, step) in &self.note_to_steps.clone() {
+            let waveform = self.generate_waveform(note, *step)?;
             self.instrument_cache.insert(note.clone(), waveform);
         }
-        
+
         log::info!("Pregenerated {} waveforms", self.instrument_cache.len());
         Ok(())
     }
 
-    fn generate_waveform(&self, _note: &str, semitones: i32) -> Result<Vec<f32>> {
+    fn generate_waveform(&self, _note: &str, step: i32) -> Result<Vec<f32>> {
         let base_freq = self.music_config.base_frequencies
             .get(&self.basetone)
             .copied()
             .ok_or_else(|| anyhow!("Unknown basetone: {}", self.basetone))?;
 
-        let frequency = base_freq * (2.0_f32).powf(semitones as f32 / 12.0);
+        let frequency = self.tuning.frequency(base_freq, step);
         let samples = (self.sample_rate as f32 * self.duration) as usize;
         
         let mut waveform = Vec::with_capacity(samples);
@@ -223,18 +229,35 @@ impl PianoSound {
         }
     }
 
-    pub fn play_note(&self, note: &str) -> Result<()> {
-        if let Some(waveform) = self.instrument_cache.get(note) {
-            let source = WaveformSource::new(waveform.clone(), self.sample_rate);
-            
-            if let Ok(sink_guard) = self.sink.lock() {
-                // Stop previous notes for monophonic behavior (like piano mode)
-                sink_guard.stop();
-                sink_guard.append(source);
-                sink_guard.play();
-            }
-        } else {
-            log::warn!("Unknown note: {}", note);
+    pub fn play_note(&mut self, note: &str) -> Result<()> {
+        let waveform = match self.instrument_cache.get(note) {
+            Some(waveform) => waveform.clone(),
+            None => match parse_raw_step(note) {
+                Some(step) => {
+                    let waveform = self.generate_waveform(note, step)?;
+                    self.instrument_cache.insert(note.to_string(), waveform.clone());
+                    waveform
+                }
+                None => {
+                    log::warn!("Unknown note: {}", note);
+                    return Ok(());
+                }
+            },
+        };
+
+        let source = WaveformSource::new(waveform, self.sample_rate);
+
+        let voice = Sink::try_new(&self.stream_handle)
+            .map_err(|e| anyhow!("Failed to create audio sink: {}", e))?;
+        voice.append(source);
+        voice.play();
+
+        if let Ok(mut voices) = self.voices.lock() {
+            // Drop voices that have already finished playing so the map
+            // doesn't grow without bound; re-pressing the same note
+            // retriggers it, replacing (and stopping) its old voice.
+            voices.retain(|_, existing| !existing.empty());
+            voices.insert(note.to_string(), voice);
         }
         Ok(())
     }
@@ -245,6 +268,24 @@ impl PianoSound {
         Ok(())
     }
 
+    pub fn set_tuning(&mut self, tuning: Tuning) -> Result<()> {
+        self.note_to_steps = build_note_to_steps(tuning.steps.len() as u32);
+        self.tuning = tuning;
+        self.pregenerate_waveforms()?;
+        Ok(())
+    }
+
+    pub fn get_tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    pub fn get_step_for_note(&self, note: &str) -> Option<i32> {
+        self.note_to_steps
+            .get(note)
+            .copied()
+            .or_else(|| parse_raw_step(note))
+    }
+
     pub fn set_instrument(&mut self, instrument: String) -> Result<()> {
         if !self.instruments.contains(&instrument) {
             return Err(anyhow!("Invalid instrument: {}", instrument));
@@ -267,8 +308,10 @@ impl PianoSound {
     }
 
     pub fn stop(&self) {
-        if let Ok(sink_guard) = self.sink.lock() {
-            sink_guard.stop();
+        if let Ok(mut voices) = self.voices.lock() {
+            for (_, voice) in voices.drain() {
+                voice.stop();
+            }
         }
     }
 
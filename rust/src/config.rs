@@ -69,6 +69,7 @@ impl Default for GuiConfig {
 pub struct MusicConfig {
     pub default_instrument: String,
     pub default_basetone: String,
+    pub default_tuning: String,
     pub base_frequencies: HashMap<String, f32>,
 }
 
@@ -91,11 +92,32 @@ impl Default for MusicConfig {
         Self {
             default_instrument: "piano".to_string(),
             default_basetone: "C".to_string(),
+            default_tuning: "12-TET".to_string(),
             base_frequencies,
         }
     }
 }
 
+/// The three computer-keyboard rows every layout maps onto, top to bottom.
+pub const KEYBOARD_ROWS: [&[&str]; 3] = [
+    &["q", "w", "e", "r", "t", "y", "u", "i", "o", "p", "[", "]"],
+    &["a", "s", "d", "f", "g", "h", "j", "k", "l", ";", "'"],
+    &["z", "x", "c", "v", "b", "n", "m", ",", ".", "/"],
+];
+
+/// Named isomorphic layouts: each key's note is `origin + a*column + b*row`
+/// (row counted from the bottom), so any chord shape stays the same shape
+/// wherever it's played. `(title, a, b)` are raw tuning steps, fed straight
+/// into `Tuning::frequency` rather than named via the scale-degree
+/// vocabulary -- so they count the same number of steps in whichever tuning
+/// is active (the size of a step in cents depends on that tuning) and never
+/// need regenerating when the tuning changes.
+pub const ISOMORPHIC_LAYOUTS: [(&str, i32, i32); 3] = [
+    ("Wicki-Hayden", 2, 7),
+    ("Harmonic Table", 7, 4),
+    ("Guitar-style (fourths)", 1, 5),
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyboardLayout {
     pub title: String,
@@ -144,10 +166,48 @@ impl KeyboardLayout {
         if layouts.is_empty() {
             layouts.push(Self::create_default_layout());
         }
-        
+
+        layouts.extend(Self::isomorphic_layouts());
+
         layouts
     }
-    
+
+    /// Build the named isomorphic layouts (Wicki-Hayden and friends) by
+    /// generating `key_mappings` from their interval vectors.
+    pub fn isomorphic_layouts() -> Vec<KeyboardLayout> {
+        ISOMORPHIC_LAYOUTS
+            .iter()
+            .map(|&(title, a, b)| Self::from_isomorphic(title, a, b, 0))
+            .collect()
+    }
+
+    /// Generate an isomorphic layout: moving one key right adds `a` steps,
+    /// moving one key up a row adds `b` steps, from an `origin_step` (0 is
+    /// the tonic) at the bottom-left key. Steps are stored as raw-step note
+    /// names (`"@{step}"`, see `PianoSound::get_step_for_note`) since the
+    /// grid routinely spans more octaves than the three-register
+    /// scale-degree vocabulary ("1".."7", ".", "^") can name without
+    /// collisions, and a raw step is valid under any tuning.
+    pub fn from_isomorphic(title: &str, a: i32, b: i32, origin_step: i32) -> Self {
+        let mut key_mappings = HashMap::new();
+        let row_count = KEYBOARD_ROWS.len();
+
+        for (row_index, keys) in KEYBOARD_ROWS.iter().enumerate() {
+            let row_from_bottom = (row_count - 1 - row_index) as i32;
+            for (col_index, &key) in keys.iter().enumerate() {
+                let step = origin_step + a * col_index as i32 + b * row_from_bottom;
+                key_mappings.insert(key.to_string(), format!("@{}", step));
+            }
+        }
+
+        Self {
+            title: title.to_string(),
+            description: Some(format!("Isomorphic layout (right = {} steps, up a row = {} steps)", a, b)),
+            key_mappings,
+            basetone: None,
+        }
+    }
+
     /// Create a default keyboard layout
     pub fn create_default_layout() -> Self {
         let mut key_mappings = HashMap::new();
@@ -224,10 +284,25 @@ pub const SOLFEGE_DISPLAY: &[(&str, &str)] = &[
     (".#6", "low la#"),
 ];
 
-pub fn get_solfege_display(note: &str) -> String {
+/// Look up the solfege name for a 12-TET note. When the note has no 12-TET
+/// name (e.g. a step of a microtonal tuning), fall back to showing the raw
+/// step index and its offset in cents from the reference pitch.
+pub fn get_solfege_display(note: &str, step: i32, tuning: &crate::tuning::Tuning) -> String {
     SOLFEGE_DISPLAY
         .iter()
         .find(|(key, _)| *key == note)
         .map(|(_, value)| value.to_string())
-        .unwrap_or_else(|| note.to_string())
-}
\ No newline at end of file
+        .unwrap_or_else(|| format!("step {} ({:+.0}\u{a2})", step, tuning.cents_for_step(step)))
+}
+
+/// Wrap an octave/period index onto the three registers the scale-degree
+/// note names support -- "." (below), "" (base), "^" (above) -- i.e. modulo
+/// 3, centered so 0 maps to the base octave. This repeats every 3 periods
+/// (`fold_to_register(n) == fold_to_register(n + 3)`), so it only keeps
+/// chord tones distinct over the narrow octave range triads/sevenths
+/// actually span; it's not a general substitute for naming steps that span
+/// many more periods. Isomorphic layouts, which do, use the raw step
+/// integer directly instead (see `KeyboardLayout::from_isomorphic`).
+pub(crate) fn fold_to_register(octave: i32) -> i32 {
+    (octave + 1).rem_euclid(3) - 1
+}
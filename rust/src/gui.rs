@@ -1,11 +1,36 @@
 use crate::audio::PianoSound;
-use crate::config::{get_solfege_display, Config, KeyboardLayout};
+use crate::config::{fold_to_register, get_solfege_display, Config, KeyboardLayout};
+use crate::recording::{NoteEvent, NoteEventKind, Recording};
+use crate::tuning::Tuning;
 use anyhow::Result;
 use eframe::egui::{self, Color32, RichText, Ui};
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyboardView {
+    Keycap,
+    Piano,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChordMode {
+    Off,
+    Triad,
+    Seventh,
+}
+
+impl ChordMode {
+    fn label(&self) -> &'static str {
+        match self {
+            ChordMode::Off => "Off",
+            ChordMode::Triad => "Triad",
+            ChordMode::Seventh => "7th",
+        }
+    }
+}
+
 pub struct PianoApp {
     config: Config,
     piano: Arc<Mutex<PianoSound>>,
@@ -21,18 +46,40 @@ pub struct PianoApp {
     key_timers: HashMap<String, Instant>,
     selected_basetone: String,
     selected_instrument: String,
+    available_tunings: Vec<Tuning>,
+    current_tuning_index: usize,
+    keyboard_view: KeyboardView,
+    active_touches: HashMap<String, String>,
+    is_recording: bool,
+    last_record_event: Option<Instant>,
+    recorded_events: Vec<NoteEvent>,
+    is_playing: bool,
+    playback_events: Vec<NoteEvent>,
+    playback_due_ms: Vec<u64>,
+    playback_index: usize,
+    playback_start: Option<Instant>,
+    chord_mode: ChordMode,
+    strum_ms: f32,
+    pending_strums: Vec<(Instant, String)>,
 }
 
+const RECORDING_FILE: &str = "recording.json";
+
 impl PianoApp {
     pub fn new(_cc: &eframe::CreationContext<'_>) -> Result<Self> {
         let config = Config::default();
-        
+
+        // Load available tunings
+        let available_tunings = Tuning::load_all_tunings();
+        let current_tuning = available_tunings.first().cloned().unwrap_or_default();
+
         let piano = PianoSound::new(
             None,
             Some(config.audio.gui_duration),
             Some(config.music.default_instrument.clone()),
             Some(config.music.default_basetone.clone()),
             Some(config.audio.default_volume),
+            Some(current_tuning),
         )?;
 
         // Load available keyboard layouts
@@ -56,6 +103,21 @@ impl PianoApp {
             active_keys: HashSet::new(),
             highlighted_keys: HashSet::new(),
             key_timers: HashMap::new(),
+            available_tunings,
+            current_tuning_index: 0,
+            keyboard_view: KeyboardView::Keycap,
+            active_touches: HashMap::new(),
+            is_recording: false,
+            last_record_event: None,
+            recorded_events: Vec::new(),
+            is_playing: false,
+            playback_events: Vec::new(),
+            playback_due_ms: Vec::new(),
+            playback_index: 0,
+            playback_start: None,
+            chord_mode: ChordMode::Off,
+            strum_ms: 20.0,
+            pending_strums: Vec::new(),
         })
     }
 
@@ -77,7 +139,7 @@ impl PianoApp {
     }
 
     fn play_note(&self, note: &str) {
-        if let Ok(piano) = self.piano.lock() {
+        if let Ok(mut piano) = self.piano.lock() {
             if let Err(e) = piano.play_note(note) {
                 log::error!("Error playing note {}: {}", note, e);
             }
@@ -85,20 +147,247 @@ impl PianoApp {
     }
 
     fn on_key_press(&mut self, key: &str) {
-        if let Some(note) = self.key_mappings.get(key) {
+        if let Some(note) = self.key_mappings.get(key).cloned() {
             self.active_keys.insert(key.to_string());
             self.highlighted_keys.insert(key.to_string());
             self.key_timers.insert(key.to_string(), Instant::now());
-            self.play_note(note);
+            self.schedule_chord(&note);
         }
     }
 
+    /// Expand a scale-degree note into its chord tones (per `chord_mode`)
+    /// and schedule each one to play in turn, staggered by `strum_ms` across
+    /// the whole chord so the keyboard still fires a single keypress. Relies
+    /// on `PianoSound` giving each tone its own voice so the whole chord
+    /// actually sounds at once rather than the last tone cutting the rest off.
+    fn schedule_chord(&mut self, note: &str) {
+        let tones = self.chord_tones(note);
+        let step_ms = if tones.len() > 1 {
+            self.strum_ms / (tones.len() - 1) as f32
+        } else {
+            0.0
+        };
+
+        let now = Instant::now();
+        for (i, tone) in tones.into_iter().enumerate() {
+            let due = now + Duration::from_millis((step_ms * i as f32) as u64);
+            self.pending_strums.push((due, tone));
+        }
+    }
+
+    /// Play and record every scheduled chord tone whose strum delay has
+    /// elapsed. Runs every frame so staggered onsets land close to on time
+    /// without blocking the UI thread.
+    fn advance_strums(&mut self) {
+        let now = Instant::now();
+        let due: Vec<String> = {
+            let (due, pending): (Vec<_>, Vec<_>) = self.pending_strums.drain(..).partition(|(due, _)| *due <= now);
+            self.pending_strums = pending;
+            due.into_iter().map(|(_, note)| note).collect()
+        };
+
+        for note in due {
+            self.play_note(&note);
+            self.record_event(&note, NoteEventKind::Press);
+        }
+    }
+
+    /// Build the diatonic triad/seventh chord stacked on top of `note`
+    /// (a scale-degree name like "3" or "^5"). Chromatic or unrecognized
+    /// notes, and chord mode Off, pass through unchanged.
+    fn chord_tones(&self, note: &str) -> Vec<String> {
+        let offsets: &[i32] = match self.chord_mode {
+            ChordMode::Off => return vec![note.to_string()],
+            ChordMode::Triad => &[0, 2, 4],
+            ChordMode::Seventh => &[0, 2, 4, 6],
+        };
+
+        let Some((octave_level, degree0)) = parse_scale_degree(note) else {
+            return vec![note.to_string()];
+        };
+
+        offsets
+            .iter()
+            .map(|offset| {
+                let absolute = octave_level * 7 + degree0 + offset;
+                // Only "." / "" / "^" registers exist; fold rather than
+                // clamp so a seventh stacked near the edge of a register
+                // doesn't collapse onto the same pitch class as its root.
+                let new_octave = fold_to_register(absolute.div_euclid(7));
+                let new_degree0 = absolute.rem_euclid(7);
+                format_scale_degree(new_octave, new_degree0)
+            })
+            .collect()
+    }
+
     fn on_key_release(&mut self, key: &str) {
+        if let Some(note) = self.key_mappings.get(key).cloned() {
+            // Release every chord tone `on_key_press` expanded this key into,
+            // not just the root -- otherwise a recorded Triad/7th press never
+            // gets a matching Release for its upper tones.
+            for tone in self.chord_tones(&note) {
+                self.record_event(&tone, NoteEventKind::Release);
+            }
+        }
         self.active_keys.remove(key);
         self.key_timers.remove(key);
         self.highlighted_keys.remove(key);
     }
 
+    /// Append a press/release to the in-progress recording, timestamped as
+    /// milliseconds since the previous event.
+    fn record_event(&mut self, note: &str, kind: NoteEventKind) {
+        if !self.is_recording {
+            return;
+        }
+        let now = Instant::now();
+        let delta_ms = self
+            .last_record_event
+            .map(|last| now.duration_since(last).as_millis() as u64)
+            .unwrap_or(0);
+        self.last_record_event = Some(now);
+        self.recorded_events.push(NoteEvent {
+            note: note.to_string(),
+            delta_ms,
+            kind,
+        });
+    }
+
+    fn start_recording(&mut self) {
+        self.is_recording = true;
+        self.recorded_events.clear();
+        self.last_record_event = None;
+    }
+
+    fn stop_recording(&mut self) {
+        if !self.is_recording {
+            return;
+        }
+        self.is_recording = false;
+
+        let tuning_title = self
+            .available_tunings
+            .get(self.current_tuning_index)
+            .map(|tuning| tuning.title.clone())
+            .unwrap_or_default();
+
+        let recording = Recording {
+            instrument: self.selected_instrument.clone(),
+            basetone: self.selected_basetone.clone(),
+            tuning: tuning_title,
+            events: self.recorded_events.clone(),
+        };
+
+        match recording.save_to_file(RECORDING_FILE) {
+            Ok(()) => self.update_status(
+                format!("Recording saved to {} 💾", RECORDING_FILE),
+                Color32::BLUE,
+            ),
+            Err(e) => {
+                log::error!("Error saving recording: {}", e);
+                self.update_status("Failed to save recording".to_string(), Color32::RED);
+            }
+        }
+    }
+
+    fn start_playback(&mut self) {
+        let recording = match Recording::load_from_file(RECORDING_FILE) {
+            Ok(recording) => recording,
+            Err(e) => {
+                log::error!("Error loading recording: {}", e);
+                self.update_status("No recording to play back".to_string(), Color32::RED);
+                return;
+            }
+        };
+
+        let tuning_index = self
+            .available_tunings
+            .iter()
+            .position(|tuning| tuning.title == recording.tuning);
+
+        if let Ok(mut piano) = self.piano.lock() {
+            if let Err(e) = piano.set_instrument(recording.instrument.clone()) {
+                log::error!("Error setting instrument for playback: {}", e);
+            }
+            if let Err(e) = piano.set_basetone(recording.basetone.clone()) {
+                log::error!("Error setting basetone for playback: {}", e);
+            }
+            if let Some(index) = tuning_index {
+                if let Err(e) = piano.set_tuning(self.available_tunings[index].clone()) {
+                    log::error!("Error setting tuning for playback: {}", e);
+                }
+            } else {
+                log::warn!("Recording used unknown tuning '{}', keeping current tuning", recording.tuning);
+            }
+        }
+        self.selected_instrument = recording.instrument.clone();
+        self.selected_basetone = recording.basetone.clone();
+        if let Some(index) = tuning_index {
+            self.current_tuning_index = index;
+        }
+
+        let mut cumulative_ms = 0u64;
+        self.playback_due_ms = recording
+            .events
+            .iter()
+            .map(|event| {
+                cumulative_ms += event.delta_ms;
+                cumulative_ms
+            })
+            .collect();
+        self.playback_events = recording.events;
+        self.playback_index = 0;
+        self.playback_start = Some(Instant::now());
+        self.is_playing = true;
+        self.update_status("Playing back recording ▶".to_string(), Color32::BLUE);
+    }
+
+    fn key_for_note(&self, note: &str) -> Option<String> {
+        self.key_mappings
+            .iter()
+            .find(|(_, mapped_note)| mapped_note.as_str() == note)
+            .map(|(key, _)| key.clone())
+    }
+
+    fn stop_playback(&mut self) {
+        self.is_playing = false;
+        self.playback_start = None;
+    }
+
+    /// Fire every recorded event whose onset has come due, driving
+    /// `highlighted_keys` so the keyboard animates along with the playback.
+    fn advance_playback(&mut self) {
+        let Some(start) = self.playback_start else {
+            return;
+        };
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+
+        while self.playback_index < self.playback_events.len()
+            && self.playback_due_ms[self.playback_index] <= elapsed_ms
+        {
+            let event = self.playback_events[self.playback_index].clone();
+            let key = self.key_for_note(&event.note);
+            match event.kind {
+                NoteEventKind::Press => {
+                    self.play_note(&event.note);
+                    if let Some(key) = key {
+                        self.highlighted_keys.insert(key);
+                    }
+                }
+                NoteEventKind::Release => {
+                    if let Some(key) = key {
+                        self.highlighted_keys.remove(&key);
+                    }
+                }
+            }
+            self.playback_index += 1;
+        }
+
+        if self.playback_index >= self.playback_events.len() {
+            self.stop_playback();
+        }
+    }
+
     fn cleanup_stuck_keys(&mut self) {
         let now = Instant::now();
         let cleanup_delay = Duration::from_millis(1000);
@@ -173,6 +462,45 @@ impl PianoApp {
                     ui.label(format!("1={}", self.selected_basetone));
                 });
 
+                ui.horizontal(|ui| {
+                    ui.label("Tuning:");
+                    let current_tuning_name = self.available_tunings
+                        .get(self.current_tuning_index)
+                        .map(|tuning| tuning.title.clone())
+                        .unwrap_or_default();
+                    let mut tuning_changed = false;
+                    let mut new_tuning_name = String::new();
+
+                    egui::ComboBox::from_id_source("tuning")
+                        .selected_text(&current_tuning_name)
+                        .show_ui(ui, |ui| {
+                            for (index, tuning) in self.available_tunings.iter().enumerate() {
+                                let selected = ui.selectable_value(&mut self.current_tuning_index, index, &tuning.title);
+                                if selected.changed() {
+                                    tuning_changed = true;
+                                    new_tuning_name = tuning.title.clone();
+                                }
+                            }
+                        });
+
+                    if tuning_changed {
+                        let tuning = self.available_tunings[self.current_tuning_index].clone();
+                        let status_msg = if let Ok(mut piano) = self.piano.lock() {
+                            if let Err(e) = piano.set_tuning(tuning) {
+                                log::error!("Error setting tuning: {}", e);
+                                None
+                            } else {
+                                Some(format!("Tuning changed to: {} 🎼", new_tuning_name))
+                            }
+                        } else {
+                            None
+                        };
+                        if let Some(msg) = status_msg {
+                            self.update_status(msg, Color32::BLUE);
+                        }
+                    }
+                });
+
                 ui.horizontal(|ui| {
                     ui.label("Instrument:");
                     let instruments = if let Ok(piano) = self.piano.lock() {
@@ -234,6 +562,41 @@ impl PianoApp {
                     }
                 });
 
+                ui.horizontal(|ui| {
+                    ui.label("🎙 Recorder:");
+
+                    if self.is_recording {
+                        if ui.button("⏹ Stop Recording").clicked() {
+                            self.stop_recording();
+                        }
+                    } else if ui.button("⏺ Record").clicked() {
+                        self.start_recording();
+                        self.update_status("Recording... 🔴".to_string(), Color32::RED);
+                    }
+
+                    if self.is_playing {
+                        if ui.button("⏹ Stop Playback").clicked() {
+                            self.stop_playback();
+                        }
+                    } else if ui.button("▶ Play").clicked() {
+                        self.start_playback();
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("Chord:");
+                    egui::ComboBox::from_id_source("chord_mode")
+                        .selected_text(self.chord_mode.label())
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.chord_mode, ChordMode::Off, ChordMode::Off.label());
+                            ui.selectable_value(&mut self.chord_mode, ChordMode::Triad, ChordMode::Triad.label());
+                            ui.selectable_value(&mut self.chord_mode, ChordMode::Seventh, ChordMode::Seventh.label());
+                        });
+
+                    ui.label("Strum:");
+                    ui.add(egui::Slider::new(&mut self.strum_ms, 0.0..=60.0).suffix(" ms"));
+                });
+
                 ui.horizontal(|ui| {
                     ui.label("Layout:");
                     let current_layout_name = self.current_layout.title.clone();
@@ -259,29 +622,32 @@ impl PianoApp {
                         self.update_status(format!("Layout changed to: {}", new_layout_name), Color32::BLUE);
                     }
                 });
-                
+
+                ui.horizontal(|ui| {
+                    ui.label("View:");
+                    if ui.selectable_label(self.keyboard_view == KeyboardView::Keycap, "⌨ Keycap").clicked() {
+                        self.keyboard_view = KeyboardView::Keycap;
+                    }
+                    if ui.selectable_label(self.keyboard_view == KeyboardView::Piano, "🎹 Piano").clicked() {
+                        self.keyboard_view = KeyboardView::Piano;
+                    }
+                });
+
                 ui.separator();
                 ui.colored_label(self.status_color, &self.status_message);
             });
         });
     }
 
-    fn create_piano_keyboard(&mut self, ui: &mut Ui) {
+    fn create_piano_keyboard(&mut self, ui: &mut Ui) -> Vec<(String, egui::Rect)> {
+        let mut key_rects = Vec::new();
+
         ui.group(|ui| {
             ui.vertical(|ui| {
                 ui.heading(&self.current_layout.title);
                 
                 // Mac keyboard layout - multiple rows
-                let keyboard_rows = [
-                    // Top letter row
-                    vec!["q", "w", "e", "r", "t", "y", "u", "i", "o", "p", "[", "]"],
-                    // Middle letter row (main piano keys)
-                    vec!["a", "s", "d", "f", "g", "h", "j", "k", "l", ";", "'"],
-                    // Bottom letter row
-                    vec!["z", "x", "c", "v", "b", "n", "m", ",", ".", "/"],
-                ];
-
-                for (row_idx, keys) in keyboard_rows.iter().enumerate() {
+                for (row_idx, keys) in crate::config::KEYBOARD_ROWS.iter().enumerate() {
                     ui.horizontal(|ui| {
                         // Add spacing for keyboard offset
                         if row_idx == 1 {
@@ -290,7 +656,7 @@ impl PianoApp {
                             ui.add_space(40.0); // Full key offset
                         }
                         
-                        for &key in keys {
+                        for &key in keys.iter() {
                             let is_piano_key = self.key_mappings.contains_key(key);
                             let note_value = self.key_mappings.get(key).cloned().unwrap_or_default();
                             
@@ -305,7 +671,13 @@ impl PianoApp {
                             };
 
                             let display_text = if is_piano_key {
-                                let solfege_name = get_solfege_display(&note_value);
+                                let solfege_name = if let Ok(piano) = self.piano.lock() {
+                                    piano.get_step_for_note(&note_value)
+                                        .map(|step| get_solfege_display(&note_value, step, piano.get_tuning()))
+                                        .unwrap_or_else(|| note_value.clone())
+                                } else {
+                                    note_value.clone()
+                                };
                                 format!("{}\n{}\n({})", key.to_uppercase(), note_value, solfege_name)
                             } else {
                                 key.to_uppercase()
@@ -315,14 +687,118 @@ impl PianoApp {
                                 .fill(bg_color)
                                 .min_size(egui::vec2(50.0, 60.0));
 
-                            if ui.add(button).clicked() && is_piano_key {
-                                self.on_key_press(key);
+                            let response = ui.add(button);
+                            if is_piano_key {
+                                key_rects.push((key.to_string(), response.rect));
+                                if response.clicked() {
+                                    self.on_key_press(key);
+                                }
                             }
                         }
                     });
                 }
             });
         });
+
+        key_rects
+    }
+
+    /// Draw a realistic two-tier piano: full-width white keys with narrower
+    /// black keys overlaid at their true fractional offsets, spanning the
+    /// low/base/high octaves the keyboard layouts already address.
+    fn create_realistic_piano_keyboard(&mut self, ui: &mut Ui) -> Vec<(String, egui::Rect)> {
+        const WHITE_DEGREES: [&str; 7] = ["1", "2", "3", "4", "5", "6", "7"];
+        const BLACK_DEGREES: [(&str, f32); 5] = [
+            ("#1", 0.13),
+            ("#2", 0.30),
+            ("#4", 0.56),
+            ("#5", 0.71),
+            ("#6", 0.87),
+        ];
+        const OCTAVE_PREFIXES: [&str; 3] = [".", "", "^"];
+
+        let octave_width = 360.0;
+        let white_key_width = octave_width / WHITE_DEGREES.len() as f32;
+        let black_key_width = white_key_width * 0.6;
+        let white_key_height = 120.0;
+        let black_key_height = 75.0;
+        let total_width = octave_width * OCTAVE_PREFIXES.len() as f32;
+
+        let mut key_rects = Vec::new();
+
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                ui.heading(&self.current_layout.title);
+
+                let note_to_key: HashMap<String, String> = self.key_mappings
+                    .iter()
+                    .map(|(key, note)| (note.clone(), key.clone()))
+                    .collect();
+
+                let (response, painter) = ui.allocate_painter(
+                    egui::vec2(total_width, white_key_height),
+                    egui::Sense::click(),
+                );
+                let origin = response.rect.min;
+
+                // White keys first, so black keys paint on top of them.
+                let mut white_keys = Vec::new();
+                let mut black_keys = Vec::new();
+                for (octave_idx, prefix) in OCTAVE_PREFIXES.iter().enumerate() {
+                    let octave_x = origin.x + octave_idx as f32 * octave_width;
+                    for (degree_idx, degree) in WHITE_DEGREES.iter().enumerate() {
+                        let note = format!("{}{}", prefix, degree);
+                        let x = octave_x + degree_idx as f32 * white_key_width;
+                        let rect = egui::Rect::from_min_size(
+                            egui::pos2(x, origin.y),
+                            egui::vec2(white_key_width - 1.0, white_key_height),
+                        );
+                        white_keys.push((rect, note));
+                    }
+                    for (degree, offset) in BLACK_DEGREES.iter() {
+                        let note = format!("{}{}", prefix, degree);
+                        let x = octave_x + offset * octave_width - black_key_width / 2.0;
+                        let rect = egui::Rect::from_min_size(
+                            egui::pos2(x, origin.y),
+                            egui::vec2(black_key_width, black_key_height),
+                        );
+                        black_keys.push((rect, note));
+                    }
+                }
+
+                for (rect, note) in &white_keys {
+                    let key = note_to_key.get(note);
+                    let pressed = key.map_or(false, |k| self.highlighted_keys.contains(k));
+                    let fill = if pressed { Color32::from_rgb(255, 69, 0) } else { Color32::WHITE };
+                    painter.rect_filled(*rect, 2.0, fill);
+                    painter.rect_stroke(*rect, 2.0, egui::Stroke::new(1.0, Color32::BLACK));
+                }
+                for (rect, note) in &black_keys {
+                    let key = note_to_key.get(note);
+                    let pressed = key.map_or(false, |k| self.highlighted_keys.contains(k));
+                    let fill = if pressed { Color32::from_rgb(255, 69, 0) } else { Color32::BLACK };
+                    painter.rect_filled(*rect, 2.0, fill);
+                }
+
+                // Black keys sit on top, so they win both click and touch hit-testing ties.
+                for (rect, note) in black_keys.iter().chain(white_keys.iter()) {
+                    if let Some(key) = note_to_key.get(note) {
+                        key_rects.push((key.clone(), *rect));
+                    }
+                }
+
+                if response.clicked() {
+                    if let Some(pos) = response.interact_pointer_pos() {
+                        let hit = key_rects.iter().find(|(_, rect)| rect.contains(pos));
+                        if let Some((key, _)) = hit {
+                            self.on_key_press(&key.clone());
+                        }
+                    }
+                }
+            });
+        });
+
+        key_rects
     }
 
     fn create_instructions(&self, ui: &mut Ui) {
@@ -343,6 +819,47 @@ impl PianoApp {
         });
     }
 
+    /// Drive multiple simultaneously-held notes from touchscreen input: a new
+    /// touch landing on a key presses it, a touch sliding onto another key
+    /// releases the old note and presses the new one, and a lifted/cancelled
+    /// touch releases whatever key it was holding.
+    fn process_touch_input(&mut self, ctx: &egui::Context, key_rects: &[(String, egui::Rect)]) {
+        let input = ctx.input(|i| i.clone());
+
+        for event in &input.events {
+            if let egui::Event::Touch { id, phase, pos, .. } = event {
+                let touch_id = format!("{:?}", id);
+                let key_under = key_rects
+                    .iter()
+                    .find(|(_, rect)| rect.contains(*pos))
+                    .map(|(key, _)| key.clone());
+
+                match phase {
+                    egui::TouchPhase::Start | egui::TouchPhase::Move => {
+                        let previous = self.active_touches.get(&touch_id).cloned();
+                        if previous != key_under {
+                            if let Some(old_key) = previous {
+                                self.on_key_release(&old_key);
+                            }
+                            if let Some(new_key) = &key_under {
+                                self.on_key_press(new_key);
+                            }
+                            match key_under {
+                                Some(new_key) => { self.active_touches.insert(touch_id, new_key); }
+                                None => { self.active_touches.remove(&touch_id); }
+                            }
+                        }
+                    }
+                    egui::TouchPhase::End | egui::TouchPhase::Cancel => {
+                        if let Some(old_key) = self.active_touches.remove(&touch_id) {
+                            self.on_key_release(&old_key);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn handle_keyboard_input(&mut self, ctx: &egui::Context) {
         let input = ctx.input(|i| i.clone());
         
@@ -429,25 +946,68 @@ impl eframe::App for PianoApp {
         self.check_status_timer();
         self.cleanup_stuck_keys();
         self.restore_highlighted_keys();
-        
+        self.advance_playback();
+        self.advance_strums();
+
         // Request continuous repaints for animations
         ctx.request_repaint();
 
+        let mut key_rects = Vec::new();
+
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.vertical(|ui| {
                 // Control panel
                 self.create_control_panel(ui);
-                
+
                 ui.add_space(10.0);
-                
+
                 // Piano keyboard
-                self.create_piano_keyboard(ui);
-                
+                key_rects = match self.keyboard_view {
+                    KeyboardView::Keycap => self.create_piano_keyboard(ui),
+                    KeyboardView::Piano => self.create_realistic_piano_keyboard(ui),
+                };
+
                 ui.add_space(10.0);
-                
+
                 // Instructions
                 self.create_instructions(ui);
             });
         });
+
+        // Multitouch: handle after layout so key rects reflect this frame.
+        self.process_touch_input(ctx, &key_rects);
+    }
+}
+
+/// Split a scale-degree note name (e.g. ".5", "3", "^#1") into an octave
+/// level (-1/0/1 for low/base/high) and a zero-indexed degree (0..7).
+/// Returns `None` for chromatic (sharp) or otherwise unrecognized notes.
+fn parse_scale_degree(note: &str) -> Option<(i32, i32)> {
+    let (octave_level, rest) = if let Some(rest) = note.strip_prefix('.') {
+        (-1, rest)
+    } else if let Some(rest) = note.strip_prefix('^') {
+        (1, rest)
+    } else {
+        (0, note)
+    };
+
+    if rest.contains('#') {
+        return None;
     }
-}
\ No newline at end of file
+
+    let degree: i32 = rest.parse().ok()?;
+    if !(1..=7).contains(&degree) {
+        return None;
+    }
+
+    Some((octave_level, degree - 1))
+}
+
+fn format_scale_degree(octave_level: i32, degree0: i32) -> String {
+    let prefix = match octave_level {
+        -1 => ".",
+        1 => "^",
+        _ => "",
+    };
+    format!("{}{}", prefix, degree0 + 1)
+}
@@ -0,0 +1,5 @@
+pub mod audio;
+pub mod config;
+pub mod gui;
+pub mod recording;
+pub mod tuning;
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteEventKind {
+    Press,
+    Release,
+}
+
+/// A single recorded note event: which note, how long after the previous
+/// event it happened, and whether it was a press or a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteEvent {
+    pub note: String,
+    pub delta_ms: u64,
+    pub kind: NoteEventKind,
+}
+
+/// A captured performance: enough header info to reproduce the sound plus
+/// the press/release timeline, persisted as plain JSON so a session can be
+/// reloaded later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recording {
+    pub instrument: String,
+    pub basetone: String,
+    pub tuning: String,
+    pub events: Vec<NoteEvent>,
+}
+
+impl Recording {
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content)?;
+        Ok(())
+    }
+
+    pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let recording: Recording = serde_json::from_str(&content)?;
+        Ok(recording)
+    }
+}
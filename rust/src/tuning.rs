@@ -0,0 +1,162 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// A tuning system: the ratios of each scale step within one period (e.g. an
+/// octave), plus the ratio of the period itself. Step 0 is always the unison
+/// (ratio 1.0); frequencies for steps outside the first period are reached by
+/// stacking whole periods on top.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tuning {
+    pub title: String,
+    pub description: Option<String>,
+    pub steps: Vec<f32>,
+    pub period_ratio: f32,
+}
+
+impl Tuning {
+    /// Build an equal division of the period (e.g. 12-EDO, 19-EDO, 31-EDO).
+    pub fn equal_division(title: &str, steps_per_period: u32, period_ratio: f32) -> Self {
+        let steps = (0..steps_per_period)
+            .map(|k| period_ratio.powf(k as f32 / steps_per_period as f32))
+            .collect();
+
+        Self {
+            title: title.to_string(),
+            description: Some(format!("{}-EDO", steps_per_period)),
+            steps,
+            period_ratio,
+        }
+    }
+
+    /// Standard 12-tone equal temperament, matching the frequencies the rest
+    /// of the app has always used.
+    pub fn twelve_tet() -> Self {
+        Self::equal_division("12-TET", 12, 2.0)
+    }
+
+    /// Load a Scala-style `.scl` scale file: a description line, a note count,
+    /// then that many pitch lines (ratios like `3/2`, or cents like `701.96`).
+    /// The final note is taken as the period (usually the octave, 2/1).
+    pub fn from_scala_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        let mut lines = content.lines().filter(|line| !line.trim_start().starts_with('!'));
+
+        let description = lines
+            .next()
+            .ok_or_else(|| anyhow!("scale file {:?} is missing a description line", path))?
+            .trim()
+            .to_string();
+
+        let note_count: usize = lines
+            .next()
+            .ok_or_else(|| anyhow!("scale file {:?} is missing a note count", path))?
+            .trim()
+            .split_whitespace()
+            .next()
+            .unwrap_or("0")
+            .parse()?;
+
+        let mut ratios = Vec::with_capacity(note_count);
+        for line in lines.take(note_count) {
+            let token = line
+                .trim()
+                .split_whitespace()
+                .next()
+                .ok_or_else(|| anyhow!("scale file {:?} has a blank pitch line", path))?;
+            ratios.push(parse_scala_pitch(token)?);
+        }
+
+        let period_ratio = ratios
+            .pop()
+            .ok_or_else(|| anyhow!("scale file {:?} has no notes", path))?;
+
+        let mut steps = vec![1.0];
+        steps.append(&mut ratios);
+
+        let title = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| description.clone());
+
+        Ok(Self {
+            title,
+            description: Some(description),
+            steps,
+            period_ratio,
+        })
+    }
+
+    /// Load every tuning available to the app: the standard equal divisions
+    /// plus any Scala `.scl` files found in the config directory. Other file
+    /// types there are ignored.
+    pub fn load_all_tunings() -> Vec<Tuning> {
+        let mut tunings = vec![
+            Self::twelve_tet(),
+            Self::equal_division("19-EDO", 19, 2.0),
+            Self::equal_division("31-EDO", 31, 2.0),
+        ];
+
+        let config_dirs = [
+            "../config",        // From rust/target/release/
+            "../../config",     // Alternative path
+            "config",           // If run from project root
+            "../../../config",  // From rust/target/debug/
+        ];
+
+        for config_dir in &config_dirs {
+            if let Ok(entries) = fs::read_dir(config_dir) {
+                for entry in entries.flatten() {
+                    let path = entry.path();
+                    if let Some(extension) = path.extension() {
+                        if extension == "scl" {
+                            if let Ok(tuning) = Self::from_scala_file(&path) {
+                                tunings.push(tuning);
+                            }
+                        }
+                    }
+                }
+                break; // Found a valid config directory, stop looking
+            }
+        }
+
+        tunings
+    }
+
+    /// The frequency of scale step `n` (may be negative or span several
+    /// periods) relative to a reference frequency for step 0.
+    pub fn frequency(&self, ref_freq: f32, n: i32) -> f32 {
+        let steps_per_period = self.steps.len() as i32;
+        let period = n.div_euclid(steps_per_period);
+        let step_index = n.rem_euclid(steps_per_period) as usize;
+        ref_freq * self.period_ratio.powi(period) * self.steps[step_index]
+    }
+
+    /// Cents of scale step `n` above the reference frequency, for display
+    /// when a step has no conventional note name.
+    pub fn cents_for_step(&self, n: i32) -> f32 {
+        let steps_per_period = self.steps.len() as i32;
+        let period = n.div_euclid(steps_per_period);
+        let step_index = n.rem_euclid(steps_per_period) as usize;
+        1200.0 * (self.period_ratio.powi(period) * self.steps[step_index]).log2()
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::twelve_tet()
+    }
+}
+
+fn parse_scala_pitch(token: &str) -> Result<f32> {
+    if let Some((numerator, denominator)) = token.split_once('/') {
+        Ok(numerator.parse::<f32>()? / denominator.parse::<f32>()?)
+    } else if token.contains('.') {
+        let cents: f32 = token.parse()?;
+        Ok(2.0_f32.powf(cents / 1200.0))
+    } else {
+        token.parse::<f32>().map_err(Into::into)
+    }
+}